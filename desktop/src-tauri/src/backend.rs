@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use serde_json::Value;
+use tauri::State;
+
+use crate::settings::SettingsState;
+
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_millis(500);
+const HEALTH_CHECK_RETRY_INTERVAL: Duration = Duration::from_millis(250);
+const HEALTH_CHECK_MAX_ATTEMPTS: u32 = 40; // ~10s of retries
+
+fn base_url(port: u16) -> String {
+    format!("http://127.0.0.1:{port}")
+}
+
+/// Proxies `method path` (with an optional JSON body) to the sidecar and returns its
+/// decoded JSON response.
+#[tauri::command]
+pub async fn backend_request(
+    settings: State<'_, SettingsState>,
+    method: String,
+    path: String,
+    body: Option<Value>,
+) -> Result<Value, String> {
+    let port = settings.0.lock().unwrap().sidecar_port;
+    let method = method
+        .parse::<reqwest::Method>()
+        .map_err(|_| format!("'{method}' is not a valid HTTP method"))?;
+
+    let client = reqwest::Client::new();
+    let mut request = client.request(method, format!("{}{path}", base_url(port)));
+    if let Some(body) = body {
+        request = request.json(&body);
+    }
+
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("sidecar returned {status}: {body}"));
+    }
+
+    response.json::<Value>().await.map_err(|e| e.to_string())
+}
+
+/// Polls the sidecar's `/healthz` endpoint until it answers, so the UI can show a
+/// "connecting" state during startup instead of firing requests at a closed port.
+/// Returns `false` if it has not answered after `HEALTH_CHECK_MAX_ATTEMPTS` attempts.
+#[tauri::command]
+pub async fn backend_ready(settings: State<'_, SettingsState>) -> Result<bool, String> {
+    let port = settings.0.lock().unwrap().sidecar_port;
+    let url = format!("{}/healthz", base_url(port));
+    let client = reqwest::Client::builder()
+        .timeout(HEALTH_CHECK_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+
+    for _ in 0..HEALTH_CHECK_MAX_ATTEMPTS {
+        if let Ok(response) = client.get(&url).send().await {
+            if response.status().is_success() {
+                return Ok(true);
+            }
+        }
+        tokio::time::sleep(HEALTH_CHECK_RETRY_INTERVAL).await;
+    }
+
+    Ok(false)
+}
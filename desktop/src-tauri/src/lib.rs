@@ -1,4 +1,10 @@
-use tauri_plugin_shell::ShellExt;
+mod backend;
+mod settings;
+mod shortcuts;
+mod sidecar;
+mod watcher;
+
+use tauri::Manager;
 
 #[tauri::command]
 fn greet(name: &str) -> String {
@@ -6,7 +12,7 @@ fn greet(name: &str) -> String {
 }
 
 #[tauri::command]
-fn check_default_messages_path() -> Option<String> {
+pub(crate) fn check_default_messages_path() -> Option<String> {
     // Check if ~/Library/Messages/chat.db exists
     if let Some(home_dir) = dirs::home_dir() {
         let messages_path = home_dir.join("Library").join("Messages").join("chat.db");
@@ -23,7 +29,7 @@ fn check_directory_exists(path: String) -> bool {
 }
 
 #[tauri::command]
-fn get_documents_dir() -> Option<String> {
+pub(crate) fn get_documents_dir() -> Option<String> {
     dirs::document_dir().and_then(|path| path.to_str().map(|s| s.to_string()))
 }
 
@@ -33,17 +39,47 @@ pub fn run() {
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .manage(sidecar::SidecarState::new())
         .setup(|app| {
-            // Start the Go backend server as a sidecar
-            // Tauri automatically appends the target triple to the binary name
-            let sidecar = app.shell().sidecar("threadbound")?;
-            let (_rx, _child) = sidecar
-                .args(["serve", "--port", "8765"])
-                .spawn()?;
+            let handle = app.handle().clone();
+            let loaded = settings::load(&handle);
+            let port = loaded.sidecar_port;
+
+            // Start the Go backend server as a supervised sidecar; it is
+            // respawned with backoff if it ever terminates.
+            sidecar::spawn_supervised(handle.clone(), port);
+
+            // React to the Messages DB and export dir changing instead of polling.
+            watcher::watch(
+                handle.clone(),
+                loaded.messages_db_path.clone(),
+                loaded.output_dir.clone(),
+            );
+
+            shortcuts::register(&handle, &loaded.toggle_shortcut, &loaded.export_shortcut)?;
+
+            app.manage(settings::SettingsState(std::sync::Mutex::new(loaded)));
 
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![greet, check_default_messages_path, check_directory_exists, get_documents_dir])
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            check_default_messages_path,
+            check_directory_exists,
+            get_documents_dir,
+            sidecar::restart_backend,
+            settings::get_settings,
+            settings::set_settings,
+            shortcuts::set_shortcut,
+            backend::backend_request,
+            backend::backend_ready
+        ])
+        .on_window_event(|window, event| {
+            if let tauri::WindowEvent::CloseRequested { .. } = event {
+                sidecar::shutdown(&window.state::<sidecar::SidecarState>());
+            }
+        })
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
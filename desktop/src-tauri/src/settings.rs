@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+
+const SETTINGS_FILE: &str = "settings.json";
+const DEFAULT_SIDECAR_PORT: u16 = 8765;
+
+/// User-configurable app settings, persisted as JSON under the app config dir.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Settings {
+    pub messages_db_path: Option<String>,
+    pub output_dir: Option<String>,
+    pub sidecar_port: u16,
+    pub toggle_shortcut: String,
+    pub export_shortcut: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            messages_db_path: crate::check_default_messages_path(),
+            output_dir: crate::get_documents_dir(),
+            sidecar_port: DEFAULT_SIDECAR_PORT,
+            toggle_shortcut: crate::shortcuts::DEFAULT_TOGGLE_SHORTCUT.to_string(),
+            export_shortcut: crate::shortcuts::DEFAULT_EXPORT_SHORTCUT.to_string(),
+        }
+    }
+}
+
+pub struct SettingsState(pub Mutex<Settings>);
+
+fn settings_path(app: &AppHandle) -> tauri::Result<PathBuf> {
+    let dir = app.path().app_config_dir()?;
+    fs::create_dir_all(&dir)?;
+    Ok(dir.join(SETTINGS_FILE))
+}
+
+/// Loads settings from disk, falling back to defaults when the file is absent or invalid.
+pub fn load(app: &AppHandle) -> Settings {
+    settings_path(app)
+        .ok()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+pub(crate) fn save(app: &AppHandle, settings: &Settings) -> Result<(), String> {
+    let path = settings_path(app).map_err(|e| e.to_string())?;
+    let contents = serde_json::to_string_pretty(settings).map_err(|e| e.to_string())?;
+    fs::write(path, contents).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_settings(state: tauri::State<SettingsState>) -> Settings {
+    state.0.lock().unwrap().clone()
+}
+
+#[tauri::command]
+pub fn set_settings(
+    app: AppHandle,
+    state: tauri::State<SettingsState>,
+    settings: Settings,
+) -> Result<(), String> {
+    save(&app, &settings)?;
+    *state.0.lock().unwrap() = settings;
+    Ok(())
+}
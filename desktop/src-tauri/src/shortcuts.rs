@@ -0,0 +1,96 @@
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+use crate::settings::SettingsState;
+
+pub const DEFAULT_TOGGLE_SHORTCUT: &str = "CmdOrCtrl+Shift+T";
+pub const DEFAULT_EXPORT_SHORTCUT: &str = "CmdOrCtrl+Shift+E";
+
+fn toggle_main_window(app: &AppHandle) {
+    let Some(window) = app.get_webview_window("main") else {
+        return;
+    };
+    if window.is_visible().unwrap_or(false) {
+        let _ = window.hide();
+    } else {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+fn handler(is_toggle: bool) -> impl Fn(&AppHandle, &Shortcut, tauri_plugin_global_shortcut::ShortcutEvent) {
+    move |app, _shortcut, event| {
+        if event.state() != ShortcutState::Pressed {
+            return;
+        }
+        if is_toggle {
+            toggle_main_window(app);
+        } else {
+            let _ = app.emit("export-current-thread", ());
+        }
+    }
+}
+
+/// Registers the window-toggle and quick-export global shortcuts.
+pub fn register(app: &AppHandle, toggle: &str, export: &str) -> tauri::Result<()> {
+    app.global_shortcut()
+        .on_shortcut(toggle.parse::<Shortcut>()?, handler(true))?;
+    app.global_shortcut()
+        .on_shortcut(export.parse::<Shortcut>()?, handler(false))?;
+    Ok(())
+}
+
+/// Registers `accelerator` in place of the slot's current binding, then unregisters the
+/// old one and persists the change.
+/// `which` selects which slot ("toggle" or "export") is being rebound.
+#[tauri::command]
+pub fn set_shortcut(
+    app: AppHandle,
+    settings_state: tauri::State<SettingsState>,
+    which: String,
+    accelerator: String,
+) -> Result<(), String> {
+    let is_toggle = match which.as_str() {
+        "toggle" => true,
+        "export" => false,
+        other => return Err(format!("unknown shortcut slot: {other}")),
+    };
+
+    let old = {
+        let settings = settings_state.0.lock().unwrap();
+        if is_toggle {
+            settings.toggle_shortcut.clone()
+        } else {
+            settings.export_shortcut.clone()
+        }
+    };
+
+    if accelerator == old {
+        // Nothing to rebind; re-registering an already-registered shortcut would be
+        // reported as "taken" by the plugin even though it's taken by this same slot.
+        return Ok(());
+    }
+
+    let new_shortcut = accelerator
+        .parse::<Shortcut>()
+        .map_err(|_| format!("'{accelerator}' is not a valid shortcut"))?;
+
+    // Register the new binding before tearing down the old one: if the new combo is
+    // already taken by the OS, the user keeps their working shortcut instead of losing
+    // it with no way to get it back short of restarting the app.
+    app.global_shortcut()
+        .on_shortcut(new_shortcut, handler(is_toggle))
+        .map_err(|_| format!("'{accelerator}' is already registered by another application"))?;
+
+    if let Ok(old_shortcut) = old.parse::<Shortcut>() {
+        let _ = app.global_shortcut().unregister(old_shortcut);
+    }
+
+    let mut settings = settings_state.0.lock().unwrap();
+    if is_toggle {
+        settings.toggle_shortcut = accelerator;
+    } else {
+        settings.export_shortcut = accelerator;
+    }
+    crate::settings::save(&app, &settings)
+}
@@ -0,0 +1,129 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
+use tokio::sync::Notify;
+
+const INITIAL_BACKOFF_MS: u64 = 250;
+const MAX_BACKOFF_MS: u64 = 8_000;
+const HEALTHY_RUN: Duration = Duration::from_secs(30);
+
+/// Holds the currently running sidecar child so it can be killed or restarted, a flag
+/// the supervisor loop checks before every respawn so a deliberate shutdown can't race a
+/// fresh sidecar process into existence, and a notifier that lets `restart_backend` wake
+/// the loop early while it's sleeping out a backoff.
+pub struct SidecarState {
+    child: Mutex<Option<CommandChild>>,
+    shutting_down: AtomicBool,
+    restart_requested: Notify,
+}
+
+impl SidecarState {
+    pub fn new() -> Self {
+        Self {
+            child: Mutex::new(None),
+            shutting_down: AtomicBool::new(false),
+            restart_requested: Notify::new(),
+        }
+    }
+}
+
+/// Spawns the `threadbound` sidecar and keeps it alive, forwarding its output to the
+/// webview and respawning with exponential backoff if it ever terminates.
+pub fn spawn_supervised(app: AppHandle, port: u16) {
+    tauri::async_runtime::spawn(async move {
+        let mut backoff = Duration::from_millis(INITIAL_BACKOFF_MS);
+
+        loop {
+            if app.state::<SidecarState>().shutting_down.load(Ordering::Acquire) {
+                return;
+            }
+
+            let started_at = Instant::now();
+
+            match spawn_once(&app, port) {
+                Ok(mut rx) => {
+                    while let Some(event) = rx.recv().await {
+                        match event {
+                            CommandEvent::Stdout(line) | CommandEvent::Stderr(line) => {
+                                let _ = app.emit("sidecar-log", String::from_utf8_lossy(&line).into_owned());
+                            }
+                            CommandEvent::Error(message) => {
+                                let _ = app.emit("sidecar-crashed", message);
+                                break;
+                            }
+                            CommandEvent::Terminated(payload) => {
+                                let _ = app.emit("sidecar-crashed", payload.code);
+                                break;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Err(err) => {
+                    let _ = app.emit("sidecar-crashed", err.to_string());
+                }
+            }
+
+            let state = app.state::<SidecarState>();
+            state.child.lock().unwrap().take();
+
+            if state.shutting_down.load(Ordering::Acquire) {
+                return;
+            }
+
+            backoff = if started_at.elapsed() >= HEALTHY_RUN {
+                Duration::from_millis(INITIAL_BACKOFF_MS)
+            } else {
+                (backoff * 2).min(Duration::from_millis(MAX_BACKOFF_MS))
+            };
+
+            tokio::select! {
+                _ = tokio::time::sleep(backoff) => {}
+                _ = state.restart_requested.notified() => {
+                    backoff = Duration::from_millis(INITIAL_BACKOFF_MS);
+                }
+            }
+        }
+    });
+}
+
+fn spawn_once(
+    app: &AppHandle,
+    port: u16,
+) -> tauri_plugin_shell::Result<tokio::sync::mpsc::Receiver<CommandEvent>> {
+    let sidecar = app.shell().sidecar("threadbound")?;
+    let (rx, child) = sidecar.args(["serve", "--port", &port.to_string()]).spawn()?;
+    let state = app.state::<SidecarState>();
+    let mut slot = state.child.lock().unwrap();
+    // Re-check under the lock: `shutdown` may have run while this process was starting.
+    if state.shutting_down.load(Ordering::Acquire) {
+        let _ = child.kill();
+    } else {
+        *slot = Some(child);
+    }
+    Ok(rx)
+}
+
+/// Kills the running sidecar, if any, and wakes the supervisor loop so it respawns
+/// immediately instead of waiting out whatever backoff it's currently sleeping through.
+#[tauri::command]
+pub fn restart_backend(state: tauri::State<SidecarState>) {
+    if let Some(child) = state.child.lock().unwrap().take() {
+        let _ = child.kill();
+    }
+    state.restart_requested.notify_one();
+}
+
+/// Marks the sidecar for shutdown and kills the running child, if any. The supervisor
+/// loop checks the flag before every respawn, so this stops it from spawning a fresh
+/// process into a window that's already tearing down.
+pub fn shutdown(state: &SidecarState) {
+    state.shutting_down.store(true, Ordering::Release);
+    if let Some(child) = state.child.lock().unwrap().take() {
+        let _ = child.kill();
+    }
+}
@@ -0,0 +1,76 @@
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use notify::RecursiveMode;
+use notify_debouncer_mini::{new_debouncer, DebounceEventResult};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// mtime/size snapshot of a watched path, sent along with change events so the
+/// frontend can tell whether anything actually changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileStat {
+    pub mtime_secs: u64,
+    pub size: u64,
+}
+
+fn stat(path: &Path) -> Option<FileStat> {
+    let meta = std::fs::metadata(path).ok()?;
+    let mtime_secs = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(FileStat { mtime_secs, size: meta.len() })
+}
+
+/// Watches the configured `chat.db` and output directory, emitting debounced
+/// `messages-db-changed` / `output-dir-changed` events so the frontend can live-refresh
+/// instead of polling.
+pub fn watch(app: AppHandle, messages_db_path: Option<String>, output_dir: Option<String>) {
+    if messages_db_path.is_none() && output_dir.is_none() {
+        return;
+    }
+
+    std::thread::spawn(move || {
+        let callback_db_path = messages_db_path.clone();
+        let callback_output_dir = output_dir.clone();
+
+        let mut debouncer = match new_debouncer(DEBOUNCE, move |result: DebounceEventResult| {
+            let Ok(events) = result else { return };
+
+            if let Some(path) = &callback_db_path {
+                let file_name = Path::new(path).file_name();
+                if events.iter().any(|event| event.path.file_name() == file_name) {
+                    let _ = app.emit("messages-db-changed", stat(Path::new(path)));
+                }
+            }
+
+            if let Some(dir) = &callback_output_dir {
+                if events.iter().any(|event| event.path.starts_with(dir)) {
+                    let _ = app.emit("output-dir-changed", stat(Path::new(dir)));
+                }
+            }
+        }) {
+            Ok(debouncer) => debouncer,
+            Err(_) => return,
+        };
+
+        if let Some(path) = &messages_db_path {
+            // Watch the parent directory rather than the file itself: inotify/FSEvents
+            // can't register a watch on a path that doesn't exist yet, and the whole
+            // point here is to react when the Messages DB first appears.
+            if let Some(parent) = Path::new(path).parent() {
+                let _ = debouncer.watcher().watch(parent, RecursiveMode::NonRecursive);
+            }
+        }
+        if let Some(dir) = &output_dir {
+            let _ = debouncer
+                .watcher()
+                .watch(Path::new(dir), RecursiveMode::Recursive);
+        }
+
+        // Park forever; dropping `debouncer` would tear down the watcher.
+        loop {
+            std::thread::park();
+        }
+    });
+}